@@ -0,0 +1,219 @@
+// A small directed-graph decision engine ("LangGraph for decision making"):
+// named nodes mutate `GameState`, edges route execution until an `End` is
+// reached, and the whole traversal is recorded as a replayable transcript.
+
+mod nodes;
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::game_state::GameState;
+
+const MAX_STEPS: usize = 64;
+
+/// Read-only context made available to nodes alongside the mutable `GameState`.
+pub struct Context {
+    pub action: String,
+}
+
+pub enum NextStep {
+    Goto(&'static str),
+    Branch(Vec<(Condition, &'static str)>),
+    End(String),
+}
+
+/// A condition evaluated against the current state to pick a branch edge.
+pub type Condition = Box<dyn Fn(&GameState) -> bool + Send + Sync>;
+
+pub type Node = Box<dyn Fn(&mut GameState, &Context) -> NextStep + Send + Sync>;
+
+#[derive(Debug, Serialize)]
+pub struct StepRecord {
+    pub node: String,
+    pub state_after: GameState,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Transcript {
+    pub steps: Vec<StepRecord>,
+    pub result: String,
+    pub final_state: GameState,
+}
+
+pub struct Graph {
+    entry: &'static str,
+    nodes: HashMap<&'static str, Node>,
+}
+
+impl Graph {
+    pub fn new(entry: &'static str) -> Self {
+        Self {
+            entry,
+            nodes: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, name: &'static str, node: Node) {
+        self.nodes.insert(name, node);
+    }
+
+    /// Run the graph to completion, returning a replayable transcript of
+    /// every visited node and a full snapshot of the state after it ran
+    /// (snapshots, not diffs between steps).
+    pub fn run(&self, state: &mut GameState, ctx: &Context) -> Transcript {
+        self.run_streaming(state, ctx, |_| {})
+    }
+
+    /// Like [`Graph::run`], but invokes `on_step` after each node executes so
+    /// a caller can stream intermediate results (e.g. over `app.emit`) while
+    /// a long-running traversal is still in flight.
+    pub fn run_streaming(
+        &self,
+        state: &mut GameState,
+        ctx: &Context,
+        mut on_step: impl FnMut(&StepRecord),
+    ) -> Transcript {
+        let mut steps = Vec::new();
+        let mut current = self.entry;
+
+        let result = loop {
+            if steps.len() >= MAX_STEPS {
+                break "error: max step guard tripped".to_string();
+            }
+
+            let Some(node) = self.nodes.get(current) else {
+                break format!("error: unknown node '{current}'");
+            };
+
+            let next = node(state, ctx);
+            let step = StepRecord {
+                node: current.to_string(),
+                state_after: state.clone(),
+            };
+            on_step(&step);
+            steps.push(step);
+
+            match next {
+                NextStep::Goto(name) => current = name,
+                NextStep::Branch(edges) => match edges.into_iter().find(|(cond, _)| cond(state)) {
+                    Some((_, name)) => current = name,
+                    None => break "error: no matching branch".to_string(),
+                },
+                NextStep::End(result) => break result,
+            }
+        };
+
+        Transcript {
+            steps,
+            result,
+            final_state: state.clone(),
+        }
+    }
+}
+
+pub use nodes::build_graph;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(action: &str) -> Context {
+        Context {
+            action: action.to_string(),
+        }
+    }
+
+    #[test]
+    fn goto_advances_to_the_named_node() {
+        let mut graph = Graph::new("start");
+        graph.register("start", Box::new(|_, _| NextStep::Goto("end")));
+        graph.register("end", Box::new(|_, _| NextStep::End("done".to_string())));
+
+        let transcript = graph.run(&mut GameState::default(), &ctx("noop"));
+
+        assert_eq!(transcript.result, "done");
+        assert_eq!(transcript.steps.len(), 2);
+        assert_eq!(transcript.steps[0].node, "start");
+        assert_eq!(transcript.steps[1].node, "end");
+    }
+
+    #[test]
+    fn branch_takes_the_first_matching_condition() {
+        let mut graph = Graph::new("start");
+        graph.register(
+            "start",
+            Box::new(|_, _| {
+                NextStep::Branch(vec![
+                    (Box::new(|_: &GameState| true), "first"),
+                    (Box::new(|_: &GameState| true), "second"),
+                ])
+            }),
+        );
+        graph.register("first", Box::new(|_, _| NextStep::End("first".to_string())));
+        graph.register("second", Box::new(|_, _| NextStep::End("second".to_string())));
+
+        let transcript = graph.run(&mut GameState::default(), &ctx("noop"));
+
+        assert_eq!(transcript.result, "first");
+    }
+
+    #[test]
+    fn branch_with_no_matching_condition_errors() {
+        let mut graph = Graph::new("start");
+        graph.register(
+            "start",
+            Box::new(|_, _| NextStep::Branch(vec![(Box::new(|_: &GameState| false), "unreached")])),
+        );
+
+        let transcript = graph.run(&mut GameState::default(), &ctx("noop"));
+
+        assert_eq!(transcript.result, "error: no matching branch");
+    }
+
+    #[test]
+    fn goto_of_unregistered_node_errors() {
+        let mut graph = Graph::new("start");
+        graph.register("start", Box::new(|_, _| NextStep::Goto("missing")));
+
+        let transcript = graph.run(&mut GameState::default(), &ctx("noop"));
+
+        assert_eq!(transcript.result, "error: unknown node 'missing'");
+    }
+
+    #[test]
+    fn a_cycle_trips_the_max_step_guard_instead_of_looping_forever() {
+        let mut graph = Graph::new("loop");
+        graph.register("loop", Box::new(|_, _| NextStep::Goto("loop")));
+
+        let transcript = graph.run(&mut GameState::default(), &ctx("noop"));
+
+        assert_eq!(transcript.result, "error: max step guard tripped");
+        assert_eq!(transcript.steps.len(), MAX_STEPS);
+    }
+
+    #[test]
+    fn run_streaming_invokes_the_callback_for_every_visited_node() {
+        let mut graph = Graph::new("start");
+        graph.register("start", Box::new(|_, _| NextStep::Goto("end")));
+        graph.register("end", Box::new(|_, _| NextStep::End("done".to_string())));
+
+        let mut seen = Vec::new();
+        graph.run_streaming(&mut GameState::default(), &ctx("noop"), |step| {
+            seen.push(step.node.clone())
+        });
+
+        assert_eq!(seen, vec!["start".to_string(), "end".to_string()]);
+    }
+
+    #[test]
+    fn default_scenario_graph_ends_the_turn_when_health_reaches_zero() {
+        let mut state = GameState::default();
+        state.player.health = 5;
+
+        let transcript = build_graph().run(&mut state, &ctx("hit"));
+
+        assert_eq!(transcript.result, "game_over");
+        assert_eq!(transcript.final_state.player.health, 0);
+    }
+}
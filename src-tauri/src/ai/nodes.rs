@@ -0,0 +1,39 @@
+// Default node/edge set for the decision engine. New scenarios register
+// their own nodes here (or in a sibling module) without touching the executor.
+
+use super::{Graph, NextStep};
+
+/// Build the default scenario graph: the entry `classify` node passes straight
+/// to `apply_action`, which applies the action and then branches on whether
+/// the player is still alive.
+pub fn build_graph() -> Graph {
+    let mut graph = Graph::new("classify");
+
+    graph.register(
+        "classify",
+        Box::new(|_state, _ctx| NextStep::Goto("apply_action")),
+    );
+
+    graph.register(
+        "apply_action",
+        Box::new(|state, ctx| {
+            state.apply(&ctx.action);
+            NextStep::Branch(vec![
+                (Box::new(|s: &crate::game_state::GameState| s.player.health == 0), "game_over"),
+                (Box::new(|_: &crate::game_state::GameState| true), "continue_turn"),
+            ])
+        }),
+    );
+
+    graph.register(
+        "continue_turn",
+        Box::new(|_state, _ctx| NextStep::End("continue".to_string())),
+    );
+
+    graph.register(
+        "game_over",
+        Box::new(|_state, _ctx| NextStep::End("game_over".to_string())),
+    );
+
+    graph
+}
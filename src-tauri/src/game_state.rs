@@ -0,0 +1,261 @@
+// Persistent game state: player stats, inventory, and the turn log,
+// stored behind `tauri::State<Mutex<GameState>>` and mirrored to disk.
+
+use std::fs;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+#[cfg(feature = "ai")]
+use tauri::Emitter;
+
+#[cfg(feature = "ai")]
+use crate::ai;
+
+const SAVE_FILE: &str = "save.json";
+#[cfg(feature = "ai")]
+const EVENT_AI_STEP: &str = "ai://step";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Player {
+    pub name: String,
+    pub health: i32,
+    pub score: i32,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self {
+            name: "Player".to_string(),
+            health: 100,
+            score: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameState {
+    pub player: Player,
+    pub inventory: Vec<String>,
+    pub turn_log: Vec<String>,
+    /// Not persisted: tracks whether `apply` has run since the last save/load,
+    /// so callers (e.g. the close-confirmation prompt) know if there's
+    /// anything at risk of being lost.
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl GameState {
+    pub(crate) fn apply(&mut self, action: &str) {
+        self.turn_log.push(action.to_string());
+        self.dirty = true;
+
+        match action {
+            "heal" => self.player.health = (self.player.health + 10).min(100),
+            "hit" => self.player.health = (self.player.health - 10).max(0),
+            "score" => self.player.score += 1,
+            other if other.starts_with("pickup:") => {
+                self.inventory.push(other.trim_start_matches("pickup:").to_string())
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn has_unsaved_progress(&self) -> bool {
+        self.dirty
+    }
+
+    fn save_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+        let dir = app
+            .path()
+            .app_data_dir()
+            .map_err(|e| e.to_string())?;
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        Ok(dir.join(SAVE_FILE))
+    }
+}
+
+pub type GameStateHandle = Mutex<GameState>;
+
+/// Common response shape for `update_game_state` regardless of whether the
+/// binary was built with the `ai` feature, so the frontend can parse one
+/// shape either way: `transcript` is only populated by the AI decision graph.
+#[derive(Debug, Serialize)]
+pub struct UpdateOutcome {
+    pub state: GameState,
+    pub transcript: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heal_clamps_at_full_health() {
+        let mut state = GameState {
+            player: Player {
+                health: 95,
+                ..Player::default()
+            },
+            ..GameState::default()
+        };
+
+        state.apply("heal");
+
+        assert_eq!(state.player.health, 100);
+    }
+
+    #[test]
+    fn hit_clamps_at_zero_health() {
+        let mut state = GameState {
+            player: Player {
+                health: 5,
+                ..Player::default()
+            },
+            ..GameState::default()
+        };
+
+        state.apply("hit");
+
+        assert_eq!(state.player.health, 0);
+    }
+
+    #[test]
+    fn score_increments() {
+        let mut state = GameState::default();
+
+        state.apply("score");
+        state.apply("score");
+
+        assert_eq!(state.player.score, 2);
+    }
+
+    #[test]
+    fn pickup_adds_to_inventory() {
+        let mut state = GameState::default();
+
+        state.apply("pickup:torch");
+
+        assert_eq!(state.inventory, vec!["torch".to_string()]);
+    }
+
+    #[test]
+    fn unknown_action_is_logged_but_otherwise_a_no_op() {
+        let mut state = GameState::default();
+
+        state.apply("dance");
+
+        assert_eq!(state.turn_log, vec!["dance".to_string()]);
+        assert_eq!(state.player.health, 100);
+    }
+
+    #[test]
+    fn apply_marks_state_dirty() {
+        let mut state = GameState::default();
+        assert!(!state.has_unsaved_progress());
+
+        state.apply("score");
+        assert!(state.has_unsaved_progress());
+    }
+}
+
+#[tauri::command]
+pub fn get_game_state(state: State<GameStateHandle>) -> Result<String, String> {
+    let state = state.lock().map_err(|e| e.to_string())?;
+    serde_json::to_string(&*state).map_err(|e| e.to_string())
+}
+
+/// Routes the action through the AI decision engine on a worker thread so a
+/// long turn doesn't block the webview, streaming each visited node back via
+/// [`EVENT_AI_STEP`] and returning the full transcript once the graph halts.
+#[cfg(feature = "ai")]
+#[tauri::command]
+pub async fn update_game_state(
+    app: AppHandle,
+    action: String,
+    state: State<'_, GameStateHandle>,
+) -> Result<String, String> {
+    let snapshot = {
+        let state = state.lock().map_err(|e| e.to_string())?;
+        state.clone()
+    };
+
+    let streaming_app = app.clone();
+    let action_for_graph = action.clone();
+    let mut transcript = tauri::async_runtime::spawn(async move {
+        let mut working = snapshot;
+        let ctx = ai::Context {
+            action: action_for_graph,
+        };
+        ai::build_graph().run_streaming(&mut working, &ctx, |step| {
+            let _ = streaming_app.emit(EVENT_AI_STEP, step);
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    // The traversal above ran against a snapshot taken before the worker
+    // thread was spawned, so re-apply the action to the *current* state
+    // under the lock rather than overwriting it with that stale snapshot —
+    // otherwise a second update or a load that lands while the graph is
+    // running would be silently discarded.
+    let outcome = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        state.apply(&action);
+        transcript.final_state = state.clone();
+        UpdateOutcome {
+            state: state.clone(),
+            transcript: Some(serde_json::to_value(&transcript).map_err(|e| e.to_string())?),
+        }
+    };
+    let json = serde_json::to_string(&outcome).map_err(|e| e.to_string())?;
+    crate::windows::broadcast_state(&app, &json);
+    Ok(json)
+}
+
+/// Without the `ai` feature, actions apply directly with no decision graph.
+#[cfg(not(feature = "ai"))]
+#[tauri::command]
+pub fn update_game_state(
+    app: AppHandle,
+    action: String,
+    state: State<GameStateHandle>,
+) -> Result<String, String> {
+    let json = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        state.apply(&action);
+        let outcome = UpdateOutcome {
+            state: state.clone(),
+            transcript: None,
+        };
+        serde_json::to_string(&outcome).map_err(|e| e.to_string())?
+    };
+    crate::windows::broadcast_state(&app, &json);
+    Ok(json)
+}
+
+#[tauri::command]
+pub fn save_game(app: AppHandle, state: State<GameStateHandle>) -> Result<(), String> {
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+    let path = GameState::save_path(&app)?;
+    let json = serde_json::to_string_pretty(&*state).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())?;
+    state.dirty = false;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn load_game(app: AppHandle, state: State<GameStateHandle>) -> Result<String, String> {
+    let path = GameState::save_path(&app)?;
+    let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let loaded: GameState = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+    let json = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        *state = loaded;
+        serde_json::to_string(&*state).map_err(|e| e.to_string())?
+    };
+    crate::windows::broadcast_state(&app, &json);
+    Ok(json)
+}
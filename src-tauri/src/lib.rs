@@ -0,0 +1,62 @@
+use std::sync::Mutex;
+
+use tauri::Manager;
+
+#[cfg(feature = "ai")]
+mod ai;
+mod game_state;
+#[cfg(desktop)]
+mod menu;
+mod windows;
+
+use game_state::{get_game_state, load_game, save_game, update_game_state, GameState};
+#[cfg(desktop)]
+use menu::force_close;
+use windows::open_panel;
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    let builder = tauri::Builder::default();
+
+    #[cfg(feature = "shell")]
+    let builder = builder.plugin(tauri_plugin_shell::init());
+
+    let builder = builder
+        .manage(Mutex::new(GameState::default()))
+        .invoke_handler(tauri::generate_handler![
+            get_game_state,
+            update_game_state,
+            save_game,
+            load_game,
+            open_panel,
+            #[cfg(desktop)]
+            force_close
+        ])
+        .setup(|app| {
+            #[cfg(debug_assertions)]
+            {
+                let window = app.get_webview_window("main").unwrap();
+                window.open_devtools();
+            }
+
+            #[cfg(desktop)]
+            {
+                let main_menu = menu::build(app.handle())?;
+                app.set_menu(main_menu)?;
+                menu::build_tray(app.handle())?;
+            }
+
+            Ok(())
+        });
+
+    // Menus, tray icons, and close-confirmation prompts only exist on desktop;
+    // mobile has no equivalent surface to hang them off of.
+    #[cfg(desktop)]
+    let builder = builder
+        .on_menu_event(|app, event| menu::handle_menu_event(app, event.id.as_ref()))
+        .on_window_event(|window, event| menu::guard_close(window, event));
+
+    builder
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
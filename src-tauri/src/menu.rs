@@ -0,0 +1,71 @@
+// Native application menu and system tray, wiring game actions to the
+// frontend via events and guarding window close with an unsaved-progress prompt.
+
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Emitter, Manager, Window, WindowEvent};
+
+use crate::game_state::GameStateHandle;
+
+pub const EVENT_NEW_GAME: &str = "menu://new-game";
+pub const EVENT_SAVE: &str = "menu://save";
+pub const EVENT_LOAD: &str = "menu://load";
+/// Emitted instead of closing when the window has unsaved progress; the
+/// frontend should prompt the user, then call `force_close` to proceed.
+pub const EVENT_CLOSE_REQUESTED: &str = "window://close-requested";
+
+pub fn build(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let new_game = MenuItem::with_id(app, "new_game", "New Game", true, None::<&str>)?;
+    let save = MenuItem::with_id(app, "save", "Save", true, Some("CmdOrCtrl+S"))?;
+    let load = MenuItem::with_id(app, "load", "Load", true, Some("CmdOrCtrl+O"))?;
+    let quit = PredefinedMenuItem::quit(app, Some("Quit"))?;
+
+    Menu::with_items(app, &[&new_game, &save, &load, &quit])
+}
+
+pub fn handle_menu_event(app: &AppHandle, event_id: &str) {
+    let event = match event_id {
+        "new_game" => EVENT_NEW_GAME,
+        "save" => EVENT_SAVE,
+        "load" => EVENT_LOAD,
+        _ => return,
+    };
+    let _ = app.emit(event, ());
+}
+
+pub fn build_tray(app: &AppHandle) -> tauri::Result<()> {
+    let menu = build(app)?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Prompt before closing if there is unsaved progress, instead of exiting immediately.
+/// Has no effect (the window closes normally) once progress has been saved.
+pub fn guard_close(window: &Window, event: &WindowEvent) {
+    let WindowEvent::CloseRequested { api, .. } = event else {
+        return;
+    };
+
+    let has_unsaved = window
+        .state::<GameStateHandle>()
+        .lock()
+        .map(|state| state.has_unsaved_progress())
+        .unwrap_or(false);
+
+    if has_unsaved {
+        api.prevent_close();
+        let _ = window.emit(EVENT_CLOSE_REQUESTED, window.label());
+    }
+}
+
+/// Closes the window unconditionally, bypassing `guard_close`. The frontend
+/// calls this after the user confirms they want to discard unsaved progress.
+#[tauri::command]
+pub fn force_close(window: Window) -> Result<(), String> {
+    window.destroy().map_err(|e| e.to_string())
+}
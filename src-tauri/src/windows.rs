@@ -0,0 +1,21 @@
+// Detached panel windows (inventory, map, AI dialogue, ...) that share the
+// managed GameState and stay in sync with the main window via events.
+
+use tauri::{AppHandle, Emitter, WebviewUrl, WebviewWindowBuilder};
+
+pub const EVENT_STATE_CHANGED: &str = "game://state-changed";
+
+#[tauri::command]
+pub fn open_panel(app: AppHandle, label: String, url: String) -> Result<(), String> {
+    WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(url.into()))
+        .title(&label)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Broadcast a state update to every window so panels stay in sync with the main game.
+pub fn broadcast_state(app: &AppHandle, state_json: &str) {
+    let _ = app.emit(EVENT_STATE_CHANGED, state_json);
+}